@@ -36,11 +36,100 @@ macro_rules! impl_common {
             pub fn version(&mut self) -> Result<u16, Error<E>> {
                 self.read_register(Register::VERSION)
             }
+
+            /// Set the RCOMP temperature-compensation byte directly.
+            ///
+            /// This writes only the high byte of CONFIG, preserving the
+            /// low-byte alert/sleep bits.
+            pub fn set_rcomp(&mut self, value: u8) -> Result<(), Error<E>> {
+                let config = self.read_register(Register::CONFIG)?;
+                self.write_register(Register::CONFIG, (u16::from(value) << 8) | (config & 0x00FF))
+            }
+
+            /// Adjust RCOMP for the given cell temperature.
+            ///
+            /// Implements the standard ModelGauge compensation formula:
+            /// `rcomp = rcomp0 + (temp - 20) * coeff`, using `temp_co_up`
+            /// above 20 °C and `temp_co_down` below it, clamped to a valid
+            /// byte range.
+            pub fn compensate_temperature(
+                &mut self,
+                temp_celsius: f32,
+                config: TempCompensation,
+            ) -> Result<(), Error<E>> {
+                let coeff = if temp_celsius > 20.0 {
+                    config.temp_co_up
+                } else {
+                    config.temp_co_down
+                };
+                let rcomp = f32::from(config.rcomp0) + (temp_celsius - 20.0) * coeff;
+                self.set_rcomp(rcomp.clamp(0.0, 255.0) as u8)
+            }
         }
         impl_register_access!($ic);
     };
 }
 
+#[cfg(all(test, not(feature = "async")))]
+mod tests {
+    use crate::{Max17043, TempCompensation};
+    use embedded_hal_mock::eh1::i2c::{Mock as I2cMock, Transaction as I2cTrans};
+
+    const DEV_ADDR: u8 = 0x36;
+
+    #[test]
+    fn compensate_temperature_above_20c_uses_temp_co_up() {
+        let expectations = [
+            I2cTrans::write_read(DEV_ADDR, vec![0x0C], vec![0x97, 0x05]),
+            I2cTrans::write(DEV_ADDR, vec![0x0C, 0x92, 0x05]),
+        ];
+        let mut dev = Max17043::new(I2cMock::new(&expectations));
+        dev.compensate_temperature(30.0, TempCompensation::default())
+            .unwrap();
+        dev.destroy().done();
+    }
+
+    #[test]
+    fn compensate_temperature_below_20c_uses_temp_co_down() {
+        let expectations = [
+            I2cTrans::write_read(DEV_ADDR, vec![0x0C], vec![0x97, 0x05]),
+            I2cTrans::write(DEV_ADDR, vec![0x0C, 0xC9, 0x05]),
+        ];
+        let mut dev = Max17043::new(I2cMock::new(&expectations));
+        dev.compensate_temperature(10.0, TempCompensation::default())
+            .unwrap();
+        dev.destroy().done();
+    }
+
+    #[test]
+    fn compensate_temperature_clamps_to_upper_byte_bound() {
+        let expectations = [
+            I2cTrans::write_read(DEV_ADDR, vec![0x0C], vec![0x97, 0x05]),
+            I2cTrans::write(DEV_ADDR, vec![0x0C, 0xFF, 0x05]),
+        ];
+        let mut dev = Max17043::new(I2cMock::new(&expectations));
+        dev.compensate_temperature(-100.0, TempCompensation::default())
+            .unwrap();
+        dev.destroy().done();
+    }
+
+    #[test]
+    fn compensate_temperature_clamps_to_lower_byte_bound() {
+        let expectations = [
+            I2cTrans::write_read(DEV_ADDR, vec![0x0C], vec![0x97, 0x05]),
+            I2cTrans::write(DEV_ADDR, vec![0x0C, 0x00, 0x05]),
+        ];
+        let config = TempCompensation {
+            rcomp0: 10,
+            temp_co_up: -1.0,
+            temp_co_down: -5.0,
+        };
+        let mut dev = Max17043::new(I2cMock::new(&expectations));
+        dev.compensate_temperature(50.0, config).unwrap();
+        dev.destroy().done();
+    }
+}
+
 #[cfg(feature = "async")]
 macro_rules! impl_common {
     ($ic:ident) => {
@@ -77,6 +166,36 @@ macro_rules! impl_common {
             pub async fn version(&mut self) -> Result<u16, Error<E>> {
                 self.read_register(Register::VERSION).await
             }
+
+            /// Set the RCOMP temperature-compensation byte directly.
+            ///
+            /// This writes only the high byte of CONFIG, preserving the
+            /// low-byte alert/sleep bits.
+            pub async fn set_rcomp(&mut self, value: u8) -> Result<(), Error<E>> {
+                let config = self.read_register(Register::CONFIG).await?;
+                self.write_register(Register::CONFIG, (u16::from(value) << 8) | (config & 0x00FF))
+                    .await
+            }
+
+            /// Adjust RCOMP for the given cell temperature.
+            ///
+            /// Implements the standard ModelGauge compensation formula:
+            /// `rcomp = rcomp0 + (temp - 20) * coeff`, using `temp_co_up`
+            /// above 20 °C and `temp_co_down` below it, clamped to a valid
+            /// byte range.
+            pub async fn compensate_temperature(
+                &mut self,
+                temp_celsius: f32,
+                config: TempCompensation,
+            ) -> Result<(), Error<E>> {
+                let coeff = if temp_celsius > 20.0 {
+                    config.temp_co_up
+                } else {
+                    config.temp_co_down
+                };
+                let rcomp = f32::from(config.rcomp0) + (temp_celsius - 20.0) * coeff;
+                self.set_rcomp(rcomp.clamp(0.0, 255.0) as u8).await
+            }
         }
         impl_register_access!($ic);
     };
@@ -0,0 +1,125 @@
+//! A trait abstracting common fuel-gauge operations across all devices.
+
+use crate::Error;
+
+/// Common fuel-gauge operations implemented by every device in this crate.
+///
+/// This allows board-support code to stay generic over which MAX170xx
+/// variant is fitted, the way the Linux `power_supply` layer exposes
+/// uniform properties across its own fuel-gauge drivers.
+#[cfg(not(feature = "async"))]
+pub trait FuelGauge {
+    /// I2C bus error type.
+    type Error;
+
+    /// Get state of charge of the cell as calculated by the ModelGauge
+    /// algorithm, as a percentage.
+    fn soc(&mut self) -> Result<f32, Error<Self::Error>>;
+
+    /// Get battery voltage in Volts.
+    fn voltage(&mut self) -> Result<f32, Error<Self::Error>>;
+
+    /// Get IC version.
+    fn version(&mut self) -> Result<u16, Error<Self::Error>>;
+
+    /// Software reset.
+    fn reset(&mut self) -> Result<(), Error<Self::Error>>;
+
+    /// Quick start.
+    fn quickstart(&mut self) -> Result<(), Error<Self::Error>>;
+}
+
+/// Common fuel-gauge operations implemented by every device in this crate.
+///
+/// This allows board-support code to stay generic over which MAX170xx
+/// variant is fitted, the way the Linux `power_supply` layer exposes
+/// uniform properties across its own fuel-gauge drivers.
+// `async fn` in a public trait drops the usual auto-trait (e.g. Send)
+// bounds on the returned future. The impls in this crate are for its own
+// device structs; generic callers cannot assume the futures are Send.
+#[allow(async_fn_in_trait)]
+#[cfg(feature = "async")]
+pub trait FuelGauge {
+    /// I2C bus error type.
+    type Error;
+
+    /// Get state of charge of the cell as calculated by the ModelGauge
+    /// algorithm, as a percentage.
+    async fn soc(&mut self) -> Result<f32, Error<Self::Error>>;
+
+    /// Get battery voltage in Volts.
+    async fn voltage(&mut self) -> Result<f32, Error<Self::Error>>;
+
+    /// Get IC version.
+    async fn version(&mut self) -> Result<u16, Error<Self::Error>>;
+
+    /// Software reset.
+    async fn reset(&mut self) -> Result<(), Error<Self::Error>>;
+
+    /// Quick start.
+    async fn quickstart(&mut self) -> Result<(), Error<Self::Error>>;
+}
+
+#[cfg(not(feature = "async"))]
+macro_rules! impl_fuel_gauge {
+    ($ic:ident) => {
+        impl<I2C, E> crate::FuelGauge for $ic<I2C>
+        where
+            I2C: embedded_hal::i2c::I2c<Error = E>,
+        {
+            type Error = E;
+
+            fn soc(&mut self) -> Result<f32, Error<E>> {
+                $ic::soc(self)
+            }
+
+            fn voltage(&mut self) -> Result<f32, Error<E>> {
+                $ic::voltage(self)
+            }
+
+            fn version(&mut self) -> Result<u16, Error<E>> {
+                $ic::version(self)
+            }
+
+            fn reset(&mut self) -> Result<(), Error<E>> {
+                $ic::reset(self)
+            }
+
+            fn quickstart(&mut self) -> Result<(), Error<E>> {
+                $ic::quickstart(self)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "async")]
+macro_rules! impl_fuel_gauge {
+    ($ic:ident) => {
+        impl<I2C, E> crate::FuelGauge for $ic<I2C>
+        where
+            I2C: embedded_hal_async::i2c::I2c<Error = E>,
+        {
+            type Error = E;
+
+            async fn soc(&mut self) -> Result<f32, Error<E>> {
+                $ic::soc(self).await
+            }
+
+            async fn voltage(&mut self) -> Result<f32, Error<E>> {
+                $ic::voltage(self).await
+            }
+
+            async fn version(&mut self) -> Result<u16, Error<E>> {
+                $ic::version(self).await
+            }
+
+            async fn reset(&mut self) -> Result<(), Error<E>> {
+                $ic::reset(self).await
+            }
+
+            async fn quickstart(&mut self) -> Result<(), Error<E>> {
+                $ic::quickstart(self).await
+            }
+        }
+    };
+}
@@ -12,18 +12,23 @@
 //! - Software reset. See: [`reset()`].
 //! - Quickstart. See: [`quickstart()`].
 //! - Get IC version. See: [`version()`].
+//! - Compensate for cell temperature. See: [`compensate_temperature()`].
+//! - Use any device generically through the [`FuelGauge`] trait.
 //! - Only on MAX17048/MAX17049:
 //!     - Get charge/discharge rate. See: [`charge_rate()`].
 //! - Only on MAX17048/MAX17049/MAX17058/MAX17059:
 //!     - Set table registers. See: [`set_table()`].
+//!     - Configure and service the SOC/voltage ALRT pin. See: [`status()`].
 //!
 //! [`soc()`]: struct.Max17043.html#method.soc
 //! [`voltage()`]: struct.Max17043.html#method.voltage
 //! [`reset()`]: struct.Max17043.html#method.reset
 //! [`quickstart()`]: struct.Max17043.html#method.quickstart
 //! [`version()`]: struct.Max17043.html#method.version
+//! [`compensate_temperature()`]: struct.Max17043.html#method.compensate_temperature
 //! [`charge_rate()`]: struct.Max17048.html#method.charge_rate
 //! [`set_table()`]: struct.Max17048.html#method.set_table
+//! [`status()`]: struct.Max17048.html#method.status
 //!
 //! <!-- TODO
 //! [Introductory blog post]()
@@ -157,15 +162,18 @@
 //!
 
 #![deny(unsafe_code, missing_docs)]
-#![no_std]
+#![cfg_attr(not(test), no_std)]
 
 mod types;
-pub use crate::types::Error;
+pub use crate::types::{Error, Status, TempCompensation};
 #[macro_use]
 mod common;
 #[macro_use]
 mod register_access;
 use crate::register_access::{Command, Register, ADDR};
+#[macro_use]
+mod fuel_gauge;
+pub use crate::fuel_gauge::FuelGauge;
 mod max17043_44;
 pub use crate::max17043_44::{Max17043, Max17044};
 mod max170x8_x9;
@@ -1,7 +1,9 @@
-use crate::{Command, Error, Register, ADDR};
+use crate::{Command, Error, Register, TempCompensation, ADDR};
 
 impl_common!(Max17043);
 impl_common!(Max17044);
+impl_fuel_gauge!(Max17043);
+impl_fuel_gauge!(Max17044);
 
 #[cfg(not(feature = "async"))]
 macro_rules! impl_common_4x {
@@ -1,9 +1,13 @@
-use crate::{Command, Error, Register, ADDR};
+use crate::{Command, Error, Register, Status, TempCompensation, ADDR};
 
 impl_common!(Max17048);
 impl_common!(Max17049);
 impl_common!(Max17058);
 impl_common!(Max17059);
+impl_fuel_gauge!(Max17048);
+impl_fuel_gauge!(Max17049);
+impl_fuel_gauge!(Max17058);
+impl_fuel_gauge!(Max17059);
 
 #[cfg(not(feature = "async"))]
 macro_rules! impl_common_x8_x9 {
@@ -23,6 +27,123 @@ macro_rules! impl_common_x8_x9 {
             pub fn reset(&mut self) -> Result<(), Error<E>> {
                 self.write_register(Register::COMMAND, Command::POR_X8_X9)
             }
+
+            /// Set the empty-alert SOC threshold, as a percentage (1-32 %).
+            ///
+            /// The IC asserts the ALRT pin once SOC drops below this value.
+            pub fn set_empty_alert_threshold(&mut self, percent: u8) -> Result<(), Error<E>> {
+                let athd = 32 - percent.clamp(1, 32);
+                let config = self.read_register(Register::CONFIG)?;
+                let low = (config as u8 & 0xE0) | (athd & 0x1F);
+                self.write_register(Register::CONFIG, (config & 0xFF00) | u16::from(low))
+            }
+
+            /// Enable or disable the SOC-change alert (ALSC).
+            ///
+            /// When enabled the IC asserts the ALRT pin every time SOC changes.
+            pub fn enable_soc_change_alert(&mut self, enable: bool) -> Result<(), Error<E>> {
+                let config = self.read_register(Register::CONFIG)?;
+                let config = if enable {
+                    config | 0x0040
+                } else {
+                    config & !0x0040
+                };
+                self.write_register(Register::CONFIG, config)
+            }
+
+            /// Check whether the ALRT pin is currently asserted.
+            pub fn is_alert_active(&mut self) -> Result<bool, Error<E>> {
+                let config = self.read_register(Register::CONFIG)?;
+                Ok(config & 0x0020 != 0)
+            }
+
+            /// Clear the alert flag, releasing the ALRT pin, and clear the
+            /// sticky RI/VH/VL/VR/HD/SC flags in STATUS so future alerts
+            /// can be observed.
+            pub fn clear_alert(&mut self) -> Result<(), Error<E>> {
+                let config = self.read_register(Register::CONFIG)?;
+                self.write_register(Register::CONFIG, config & !0x0020)?;
+                let status = self.read_register(Register::STATUS)?;
+                self.write_register(Register::STATUS, status & !0xFC00)
+            }
+
+            /// Get the decoded contents of the STATUS register.
+            pub fn status(&mut self) -> Result<Status, Error<E>> {
+                let status = self.read_register(Register::STATUS)?;
+                Ok(Status {
+                    reset_indicator: status & 0x8000 != 0,
+                    voltage_high: status & 0x4000 != 0,
+                    voltage_low: status & 0x2000 != 0,
+                    voltage_reset: status & 0x1000 != 0,
+                    soc_low: status & 0x0800 != 0,
+                    soc_change: status & 0x0400 != 0,
+                })
+            }
+
+            /// Set the voltage-alert window, in Volts (20 mV resolution).
+            ///
+            /// The IC asserts the ALRT pin when VCELL leaves `min_v..=max_v`.
+            pub fn set_voltage_alert_range(
+                &mut self,
+                min_v: f32,
+                max_v: f32,
+            ) -> Result<(), Error<E>> {
+                let min = ((min_v / 0.02).clamp(0.0, 255.0) + 0.5) as u8;
+                let max = ((max_v / 0.02).clamp(0.0, 255.0) + 0.5) as u8;
+                self.write_register(Register::VALRT, (u16::from(max) << 8) | u16::from(min))
+            }
+
+            /// Get the voltage-alert window, in Volts, as `(min_v, max_v)`.
+            pub fn voltage_alert_range(&mut self) -> Result<(f32, f32), Error<E>> {
+                let valrt = self.read_register(Register::VALRT)?;
+                let max = f32::from((valrt >> 8) as u8) * 0.02;
+                let min = f32::from((valrt & 0xFF) as u8) * 0.02;
+                Ok((min, max))
+            }
+
+            /// Set the VCELL reset-comparator threshold, in Volts (40 mV
+            /// resolution).
+            ///
+            /// The IC performs a reset whenever VCELL falls below this
+            /// threshold.
+            pub fn set_reset_voltage(&mut self, v: f32) -> Result<(), Error<E>> {
+                let threshold = ((v / 0.04).clamp(0.0, 127.0) + 0.5) as u16;
+                let vreset = self.read_register(Register::VRESET)?;
+                self.write_register(Register::VRESET, (vreset & 0x01FF) | (threshold << 9))
+            }
+
+            /// Set the hibernate-entry and active-return thresholds.
+            ///
+            /// `crate_threshold` is in CRATE units (%/hr, 1.6 %/hr per LSB);
+            /// the IC enters hibernate mode once the absolute CRATE value
+            /// stays below it. `active_threshold_v` is in Volts (1.25 mV
+            /// per LSB); the IC leaves hibernate mode once VCELL changes by
+            /// more than this amount.
+            pub fn set_hibernate_thresholds(
+                &mut self,
+                crate_threshold: f32,
+                active_threshold_v: f32,
+            ) -> Result<(), Error<E>> {
+                let hibrt = ((crate_threshold / 1.6).clamp(0.0, 255.0) + 0.5) as u8;
+                let actrt = ((active_threshold_v / 0.00125).clamp(0.0, 255.0) + 0.5) as u8;
+                self.write_register(Register::HIBRT, (u16::from(hibrt) << 8) | u16::from(actrt))
+            }
+
+            /// Disable hibernate mode entirely.
+            pub fn disable_hibernation(&mut self) -> Result<(), Error<E>> {
+                self.write_register(Register::HIBRT, 0x0000)
+            }
+
+            /// Force the IC to always hibernate.
+            pub fn always_hibernate(&mut self) -> Result<(), Error<E>> {
+                self.write_register(Register::HIBRT, 0xFFFF)
+            }
+
+            /// Check whether the IC is currently hibernating (HIBSTAT).
+            pub fn is_hibernating(&mut self) -> Result<bool, Error<E>> {
+                let mode = self.read_register(Register::MODE)?;
+                Ok(mode & 0x1000 != 0)
+            }
         }
         impl<I2C, E> $ic<I2C>
         where
@@ -72,6 +193,132 @@ macro_rules! impl_common_x8_x9 {
                 self.write_register(Register::COMMAND, Command::POR_X8_X9)
                     .await
             }
+
+            /// Set the empty-alert SOC threshold, as a percentage (1-32 %).
+            ///
+            /// The IC asserts the ALRT pin once SOC drops below this value.
+            pub async fn set_empty_alert_threshold(
+                &mut self,
+                percent: u8,
+            ) -> Result<(), Error<E>> {
+                let athd = 32 - percent.clamp(1, 32);
+                let config = self.read_register(Register::CONFIG).await?;
+                let low = (config as u8 & 0xE0) | (athd & 0x1F);
+                self.write_register(Register::CONFIG, (config & 0xFF00) | u16::from(low))
+                    .await
+            }
+
+            /// Enable or disable the SOC-change alert (ALSC).
+            ///
+            /// When enabled the IC asserts the ALRT pin every time SOC changes.
+            pub async fn enable_soc_change_alert(&mut self, enable: bool) -> Result<(), Error<E>> {
+                let config = self.read_register(Register::CONFIG).await?;
+                let config = if enable {
+                    config | 0x0040
+                } else {
+                    config & !0x0040
+                };
+                self.write_register(Register::CONFIG, config).await
+            }
+
+            /// Check whether the ALRT pin is currently asserted.
+            pub async fn is_alert_active(&mut self) -> Result<bool, Error<E>> {
+                let config = self.read_register(Register::CONFIG).await?;
+                Ok(config & 0x0020 != 0)
+            }
+
+            /// Clear the alert flag, releasing the ALRT pin, and clear the
+            /// sticky RI/VH/VL/VR/HD/SC flags in STATUS so future alerts
+            /// can be observed.
+            pub async fn clear_alert(&mut self) -> Result<(), Error<E>> {
+                let config = self.read_register(Register::CONFIG).await?;
+                self.write_register(Register::CONFIG, config & !0x0020)
+                    .await?;
+                let status = self.read_register(Register::STATUS).await?;
+                self.write_register(Register::STATUS, status & !0xFC00)
+                    .await
+            }
+
+            /// Get the decoded contents of the STATUS register.
+            pub async fn status(&mut self) -> Result<Status, Error<E>> {
+                let status = self.read_register(Register::STATUS).await?;
+                Ok(Status {
+                    reset_indicator: status & 0x8000 != 0,
+                    voltage_high: status & 0x4000 != 0,
+                    voltage_low: status & 0x2000 != 0,
+                    voltage_reset: status & 0x1000 != 0,
+                    soc_low: status & 0x0800 != 0,
+                    soc_change: status & 0x0400 != 0,
+                })
+            }
+
+            /// Set the voltage-alert window, in Volts (20 mV resolution).
+            ///
+            /// The IC asserts the ALRT pin when VCELL leaves `min_v..=max_v`.
+            pub async fn set_voltage_alert_range(
+                &mut self,
+                min_v: f32,
+                max_v: f32,
+            ) -> Result<(), Error<E>> {
+                let min = ((min_v / 0.02).clamp(0.0, 255.0) + 0.5) as u8;
+                let max = ((max_v / 0.02).clamp(0.0, 255.0) + 0.5) as u8;
+                self.write_register(Register::VALRT, (u16::from(max) << 8) | u16::from(min))
+                    .await
+            }
+
+            /// Get the voltage-alert window, in Volts, as `(min_v, max_v)`.
+            pub async fn voltage_alert_range(&mut self) -> Result<(f32, f32), Error<E>> {
+                let valrt = self.read_register(Register::VALRT).await?;
+                let max = f32::from((valrt >> 8) as u8) * 0.02;
+                let min = f32::from((valrt & 0xFF) as u8) * 0.02;
+                Ok((min, max))
+            }
+
+            /// Set the VCELL reset-comparator threshold, in Volts (40 mV
+            /// resolution).
+            ///
+            /// The IC performs a reset whenever VCELL falls below this
+            /// threshold.
+            pub async fn set_reset_voltage(&mut self, v: f32) -> Result<(), Error<E>> {
+                let threshold = ((v / 0.04).clamp(0.0, 127.0) + 0.5) as u16;
+                let vreset = self.read_register(Register::VRESET).await?;
+                self.write_register(Register::VRESET, (vreset & 0x01FF) | (threshold << 9))
+                    .await
+            }
+
+            /// Set the hibernate-entry and active-return thresholds.
+            ///
+            /// `crate_threshold` is in CRATE units (%/hr, 1.6 %/hr per LSB);
+            /// the IC enters hibernate mode once the absolute CRATE value
+            /// stays below it. `active_threshold_v` is in Volts (1.25 mV
+            /// per LSB); the IC leaves hibernate mode once VCELL changes by
+            /// more than this amount.
+            pub async fn set_hibernate_thresholds(
+                &mut self,
+                crate_threshold: f32,
+                active_threshold_v: f32,
+            ) -> Result<(), Error<E>> {
+                let hibrt = ((crate_threshold / 1.6).clamp(0.0, 255.0) + 0.5) as u8;
+                let actrt = ((active_threshold_v / 0.00125).clamp(0.0, 255.0) + 0.5) as u8;
+                self.write_register(Register::HIBRT, (u16::from(hibrt) << 8) | u16::from(actrt))
+                    .await
+            }
+
+            /// Disable hibernate mode entirely.
+            pub async fn disable_hibernation(&mut self) -> Result<(), Error<E>> {
+                self.write_register(Register::HIBRT, 0x0000).await
+            }
+
+            /// Force the IC to always hibernate.
+            pub async fn always_hibernate(&mut self) -> Result<(), Error<E>> {
+                self.write_register(Register::HIBRT, 0xFFFF).await
+            }
+
+            /// Check whether the IC is currently hibernating (HIBSTAT).
+            pub async fn is_hibernating(&mut self) -> Result<bool, Error<E>> {
+                let mode = self.read_register(Register::MODE).await?;
+                Ok(mode & 0x1000 != 0)
+            }
         }
         impl<I2C, E> $ic<I2C>
         where
@@ -213,3 +460,170 @@ macro_rules! impl_common_48_49 {
 
 impl_common_48_49!(Max17048);
 impl_common_48_49!(Max17049);
+
+#[cfg(all(test, not(feature = "async")))]
+mod tests {
+    use super::*;
+    use embedded_hal_mock::eh1::i2c::{Mock as I2cMock, Transaction as I2cTrans};
+
+    const DEV_ADDR: u8 = 0x36;
+
+    #[test]
+    fn set_empty_alert_threshold_encodes_athd() {
+        let expectations = [
+            I2cTrans::write_read(DEV_ADDR, vec![0x0C], vec![0x97, 0xC0]),
+            I2cTrans::write(DEV_ADDR, vec![0x0C, 0x97, 0xDF]),
+        ];
+        let mut dev = Max17048::new(I2cMock::new(&expectations));
+        dev.set_empty_alert_threshold(1).unwrap();
+        dev.destroy().done();
+    }
+
+    #[test]
+    fn set_empty_alert_threshold_clamps_below_one_percent() {
+        let expectations = [
+            I2cTrans::write_read(DEV_ADDR, vec![0x0C], vec![0x97, 0x00]),
+            I2cTrans::write(DEV_ADDR, vec![0x0C, 0x97, 0x1F]),
+        ];
+        let mut dev = Max17048::new(I2cMock::new(&expectations));
+        dev.set_empty_alert_threshold(0).unwrap();
+        dev.destroy().done();
+    }
+
+    #[test]
+    fn set_empty_alert_threshold_32_percent_is_zero_athd() {
+        let expectations = [
+            I2cTrans::write_read(DEV_ADDR, vec![0x0C], vec![0x97, 0x00]),
+            I2cTrans::write(DEV_ADDR, vec![0x0C, 0x97, 0x00]),
+        ];
+        let mut dev = Max17048::new(I2cMock::new(&expectations));
+        dev.set_empty_alert_threshold(32).unwrap();
+        dev.destroy().done();
+    }
+
+    #[test]
+    fn enable_soc_change_alert_sets_alsc_bit() {
+        let expectations = [
+            I2cTrans::write_read(DEV_ADDR, vec![0x0C], vec![0x97, 0x00]),
+            I2cTrans::write(DEV_ADDR, vec![0x0C, 0x97, 0x40]),
+        ];
+        let mut dev = Max17048::new(I2cMock::new(&expectations));
+        dev.enable_soc_change_alert(true).unwrap();
+        dev.destroy().done();
+    }
+
+    #[test]
+    fn enable_soc_change_alert_clears_alsc_bit() {
+        let expectations = [
+            I2cTrans::write_read(DEV_ADDR, vec![0x0C], vec![0x97, 0x40]),
+            I2cTrans::write(DEV_ADDR, vec![0x0C, 0x97, 0x00]),
+        ];
+        let mut dev = Max17048::new(I2cMock::new(&expectations));
+        dev.enable_soc_change_alert(false).unwrap();
+        dev.destroy().done();
+    }
+
+    #[test]
+    fn is_alert_active_decodes_alrt_bit() {
+        let expectations = [I2cTrans::write_read(DEV_ADDR, vec![0x0C], vec![0x97, 0x20])];
+        let mut dev = Max17048::new(I2cMock::new(&expectations));
+        assert!(dev.is_alert_active().unwrap());
+        dev.destroy().done();
+    }
+
+    #[test]
+    fn clear_alert_clears_config_and_status_flags() {
+        let expectations = [
+            I2cTrans::write_read(DEV_ADDR, vec![0x0C], vec![0x97, 0x20]),
+            I2cTrans::write(DEV_ADDR, vec![0x0C, 0x97, 0x00]),
+            I2cTrans::write_read(DEV_ADDR, vec![0x1A], vec![0xFC, 0x02]),
+            I2cTrans::write(DEV_ADDR, vec![0x1A, 0x00, 0x02]),
+        ];
+        let mut dev = Max17048::new(I2cMock::new(&expectations));
+        dev.clear_alert().unwrap();
+        dev.destroy().done();
+    }
+
+    #[test]
+    fn status_decodes_all_flags() {
+        let expectations = [I2cTrans::write_read(DEV_ADDR, vec![0x1A], vec![0xFC, 0x00])];
+        let mut dev = Max17048::new(I2cMock::new(&expectations));
+        let status = dev.status().unwrap();
+        assert!(status.reset_indicator);
+        assert!(status.voltage_high);
+        assert!(status.voltage_low);
+        assert!(status.voltage_reset);
+        assert!(status.soc_low);
+        assert!(status.soc_change);
+        dev.destroy().done();
+    }
+
+    #[test]
+    fn set_voltage_alert_range_encodes_min_and_max() {
+        let expectations = [I2cTrans::write(DEV_ADDR, vec![0x14, 0xD2, 0x64])];
+        let mut dev = Max17048::new(I2cMock::new(&expectations));
+        dev.set_voltage_alert_range(2.0, 4.2).unwrap();
+        dev.destroy().done();
+    }
+
+    #[test]
+    fn voltage_alert_range_decodes_min_and_max() {
+        let expectations = [I2cTrans::write_read(DEV_ADDR, vec![0x14], vec![0xD2, 0x64])];
+        let mut dev = Max17048::new(I2cMock::new(&expectations));
+        let (min, max) = dev.voltage_alert_range().unwrap();
+        assert!((min - 2.0).abs() < 1e-3);
+        assert!((max - 4.2).abs() < 1e-3);
+        dev.destroy().done();
+    }
+
+    #[test]
+    fn set_reset_voltage_preserves_lower_bits() {
+        let expectations = [
+            I2cTrans::write_read(DEV_ADDR, vec![0x18], vec![0x00, 0xAB]),
+            I2cTrans::write(DEV_ADDR, vec![0x18, 0x96, 0xAB]),
+        ];
+        let mut dev = Max17048::new(I2cMock::new(&expectations));
+        dev.set_reset_voltage(3.0).unwrap();
+        dev.destroy().done();
+    }
+
+    #[test]
+    fn set_hibernate_thresholds_encodes_both_fields() {
+        let expectations = [I2cTrans::write(DEV_ADDR, vec![0x0A, 0x0A, 0x50])];
+        let mut dev = Max17048::new(I2cMock::new(&expectations));
+        dev.set_hibernate_thresholds(16.0, 0.1).unwrap();
+        dev.destroy().done();
+    }
+
+    #[test]
+    fn disable_hibernation_writes_zero() {
+        let expectations = [I2cTrans::write(DEV_ADDR, vec![0x0A, 0x00, 0x00])];
+        let mut dev = Max17048::new(I2cMock::new(&expectations));
+        dev.disable_hibernation().unwrap();
+        dev.destroy().done();
+    }
+
+    #[test]
+    fn always_hibernate_writes_all_ones() {
+        let expectations = [I2cTrans::write(DEV_ADDR, vec![0x0A, 0xFF, 0xFF])];
+        let mut dev = Max17048::new(I2cMock::new(&expectations));
+        dev.always_hibernate().unwrap();
+        dev.destroy().done();
+    }
+
+    #[test]
+    fn is_hibernating_decodes_hibstat_bit() {
+        let expectations = [I2cTrans::write_read(DEV_ADDR, vec![0x06], vec![0x10, 0x00])];
+        let mut dev = Max17048::new(I2cMock::new(&expectations));
+        assert!(dev.is_hibernating().unwrap());
+        dev.destroy().done();
+    }
+
+    #[test]
+    fn is_hibernating_false_when_hibstat_clear() {
+        let expectations = [I2cTrans::write_read(DEV_ADDR, vec![0x06], vec![0x00, 0x00])];
+        let mut dev = Max17048::new(I2cMock::new(&expectations));
+        assert!(!dev.is_hibernating().unwrap());
+        dev.destroy().done();
+    }
+}
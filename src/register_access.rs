@@ -0,0 +1,113 @@
+//! Register and command definitions, and the low-level register access
+//! helpers shared by all devices.
+
+pub(crate) struct Register;
+
+impl Register {
+    pub(crate) const VCELL: u8 = 0x02;
+    pub(crate) const SOC: u8 = 0x04;
+    pub(crate) const MODE: u8 = 0x06;
+    pub(crate) const VERSION: u8 = 0x08;
+    pub(crate) const HIBRT: u8 = 0x0A;
+    pub(crate) const CONFIG: u8 = 0x0C;
+    pub(crate) const VALRT: u8 = 0x14;
+    pub(crate) const CRATE: u8 = 0x16;
+    pub(crate) const VRESET: u8 = 0x18;
+    pub(crate) const STATUS: u8 = 0x1A;
+    pub(crate) const COMMAND: u8 = 0xFE;
+}
+
+pub(crate) struct Command;
+
+impl Command {
+    pub(crate) const QSTRT: u16 = 0x4000;
+    pub(crate) const POR_43_44: u16 = 0x5400;
+    pub(crate) const POR_X8_X9: u16 = 0x5400;
+}
+
+pub(crate) const ADDR: u8 = 0x36;
+
+#[cfg(not(feature = "async"))]
+macro_rules! impl_register_access {
+    ($ic:ident) => {
+        impl<I2C, E> $ic<I2C>
+        where
+            I2C: embedded_hal::i2c::I2c<Error = E>,
+        {
+            pub(crate) fn read_register(&mut self, register: u8) -> Result<u16, Error<E>> {
+                let mut data = [0; 2];
+                self.i2c
+                    .write_read(ADDR, &[register], &mut data)
+                    .map_err(Error::I2C)?;
+                Ok(u16::from_be_bytes(data))
+            }
+
+            pub(crate) fn write_register(
+                &mut self,
+                register: u8,
+                value: u16,
+            ) -> Result<(), Error<E>> {
+                let data = value.to_be_bytes();
+                self.i2c
+                    .write(ADDR, &[register, data[0], data[1]])
+                    .map_err(Error::I2C)
+            }
+
+            // Only used by the x8/x9 `set_table()` table-register lock
+            // sequence; the 43/44 family never calls it.
+            #[allow(dead_code)]
+            pub(crate) fn write_u8_register(
+                &mut self,
+                register: u8,
+                value: u8,
+            ) -> Result<(), Error<E>> {
+                self.i2c.write(ADDR, &[register, value]).map_err(Error::I2C)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "async")]
+macro_rules! impl_register_access {
+    ($ic:ident) => {
+        impl<I2C, E> $ic<I2C>
+        where
+            I2C: embedded_hal_async::i2c::I2c<Error = E>,
+        {
+            pub(crate) async fn read_register(&mut self, register: u8) -> Result<u16, Error<E>> {
+                let mut data = [0; 2];
+                self.i2c
+                    .write_read(ADDR, &[register], &mut data)
+                    .await
+                    .map_err(Error::I2C)?;
+                Ok(u16::from_be_bytes(data))
+            }
+
+            pub(crate) async fn write_register(
+                &mut self,
+                register: u8,
+                value: u16,
+            ) -> Result<(), Error<E>> {
+                let data = value.to_be_bytes();
+                self.i2c
+                    .write(ADDR, &[register, data[0], data[1]])
+                    .await
+                    .map_err(Error::I2C)
+            }
+
+            // Only used by the x8/x9 `set_table()` table-register lock
+            // sequence; the 43/44 family never calls it.
+            #[allow(dead_code)]
+            pub(crate) async fn write_u8_register(
+                &mut self,
+                register: u8,
+                value: u8,
+            ) -> Result<(), Error<E>> {
+                self.i2c
+                    .write(ADDR, &[register, value])
+                    .await
+                    .map_err(Error::I2C)
+            }
+        }
+    };
+}
@@ -0,0 +1,54 @@
+//! Crate-wide types
+
+/// All possible errors in this crate
+#[derive(Debug)]
+pub enum Error<E> {
+    /// I2C bus error
+    I2C(E),
+}
+
+/// Decoded contents of the STATUS register (MAX17048/49/58/59 only).
+///
+/// Each flag is sticky: once set by the IC it remains set until cleared,
+/// see [`clear_alert()`][crate::Max17048::clear_alert].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Status {
+    /// Reset indicator. Set when the IC powers up, cleared by `clear_alert()`.
+    pub reset_indicator: bool,
+    /// Voltage-high alert. VCELL rose above the configured maximum.
+    pub voltage_high: bool,
+    /// Voltage-low alert. VCELL fell below the configured minimum.
+    pub voltage_low: bool,
+    /// Voltage-reset alert. A reset condition was detected on VCELL.
+    pub voltage_reset: bool,
+    /// SOC-low alert. SOC crossed the empty-alert threshold.
+    pub soc_low: bool,
+    /// SOC-change alert. SOC changed by at least 1 % since the last read.
+    pub soc_change: bool,
+}
+
+/// Temperature-compensation coefficients for `compensate_temperature()`.
+///
+/// The ModelGauge RCOMP model byte is calibrated at 20 °C; these
+/// coefficients adjust it for the current cell temperature. Different
+/// battery chemistries may call for different coefficients, see the
+/// datasheet's temperature compensation application note.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TempCompensation {
+    /// RCOMP value at 20 °C.
+    pub rcomp0: u8,
+    /// RCOMP adjustment per °C above 20 °C.
+    pub temp_co_up: f32,
+    /// RCOMP adjustment per °C below 20 °C.
+    pub temp_co_down: f32,
+}
+
+impl Default for TempCompensation {
+    fn default() -> Self {
+        TempCompensation {
+            rcomp0: 0x97,
+            temp_co_up: -0.5,
+            temp_co_down: -5.0,
+        }
+    }
+}